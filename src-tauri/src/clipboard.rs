@@ -0,0 +1,28 @@
+use arboard::Clipboard;
+
+/// Keep in sync with `MAX_TEXT_LEN` in `isolation/isolation.js`, which
+/// caps the equivalent freeform text (`log_session`'s `note`) coming
+/// through IPC.
+const MAX_QUOTE_LEN: usize = 10_000;
+
+/// Read the current clipboard text, trimmed and sanitized for storage as
+/// a highlight: surrounding whitespace is stripped, control characters
+/// (other than newline/tab) are dropped, and the result is capped at
+/// `MAX_QUOTE_LEN` so a huge or binary-ish clipboard blob can't bloat the
+/// data store.
+pub fn read_text() -> Result<String, String> {
+    let mut clipboard = Clipboard::new().map_err(|e| e.to_string())?;
+    let text = clipboard.get_text().map_err(|e| e.to_string())?;
+
+    let filtered: String = text
+        .chars()
+        .filter(|c| !c.is_control() || *c == '\n' || *c == '\t')
+        .take(MAX_QUOTE_LEN)
+        .collect();
+    let sanitized = filtered.trim().to_string();
+
+    if sanitized.is_empty() {
+        return Err("clipboard is empty".into());
+    }
+    Ok(sanitized)
+}
@@ -0,0 +1,112 @@
+use tauri::AppHandle;
+
+use crate::store::{self, Book, Progress, ReadingSession, Stats};
+
+#[cfg(feature = "clipboard")]
+use crate::clipboard;
+#[cfg(feature = "notifications")]
+use crate::ids::new_id;
+#[cfg(feature = "notifications")]
+use crate::reminders::{self, Reminder};
+#[cfg(feature = "global-shortcut")]
+use crate::shortcuts;
+#[cfg(feature = "clipboard")]
+use crate::store::Highlight;
+
+/// Schedule a recurring reminder to read `book_id`, firing `message` as a
+/// desktop notification every `interval_secs` seconds.
+#[cfg(feature = "notifications")]
+#[tauri::command]
+pub fn schedule_reminder(
+    app: AppHandle,
+    book_id: String,
+    interval_secs: u64,
+    message: String,
+) -> Result<String, String> {
+    let id = new_id();
+    let reminder = Reminder {
+        id: id.clone(),
+        book_id,
+        interval_secs,
+        message,
+        last_fired_at: None,
+    };
+    reminders::add(&app, reminder)?;
+    Ok(id)
+}
+
+#[cfg(feature = "notifications")]
+#[tauri::command]
+pub fn cancel_reminder(app: AppHandle, id: String) -> Result<(), String> {
+    reminders::remove(&app, &id)
+}
+
+#[cfg(feature = "notifications")]
+#[tauri::command]
+pub fn list_reminders(app: AppHandle) -> Vec<Reminder> {
+    reminders::list(&app)
+}
+
+/// Rebind the quick-capture global shortcut at runtime, persisting the
+/// choice so it survives a restart.
+#[cfg(feature = "global-shortcut")]
+#[tauri::command]
+pub fn set_quick_capture_shortcut(app: AppHandle, accelerator: String) -> Result<(), String> {
+    shortcuts::set(&app, accelerator)
+}
+
+#[cfg(feature = "global-shortcut")]
+#[tauri::command]
+pub fn get_quick_capture_shortcut(app: AppHandle) -> String {
+    shortcuts::get(&app)
+}
+
+#[tauri::command]
+pub fn add_book(
+    app: AppHandle,
+    title: String,
+    author: String,
+    total_pages: Option<u32>,
+) -> Result<Book, String> {
+    store::add_book(&app, title, author, total_pages)
+}
+
+#[tauri::command]
+pub fn update_progress(
+    app: AppHandle,
+    book_id: String,
+    current_page: u32,
+) -> Result<Progress, String> {
+    store::update_progress(&app, book_id, current_page)
+}
+
+#[tauri::command]
+pub fn log_session(
+    app: AppHandle,
+    book_id: String,
+    duration_secs: u64,
+    pages_read: u32,
+    note: Option<String>,
+) -> Result<ReadingSession, String> {
+    store::log_session(&app, book_id, duration_secs, pages_read, note)
+}
+
+#[tauri::command]
+pub fn list_books(app: AppHandle) -> Vec<Book> {
+    store::list_books(&app)
+}
+
+#[tauri::command]
+pub fn get_stats(app: AppHandle, book_id: String) -> Result<Stats, String> {
+    store::get_stats(&app, book_id)
+}
+
+/// Save whatever is currently on the clipboard as a timestamped highlight
+/// for `book_id`, so a quote copied from an e-reader can be filed away
+/// without retyping it.
+#[cfg(feature = "clipboard")]
+#[tauri::command]
+pub fn capture_quote(app: AppHandle, book_id: String) -> Result<Highlight, String> {
+    let quote = clipboard::read_text()?;
+    store::add_highlight(&app, book_id, quote)
+}
@@ -0,0 +1,6 @@
+/// A unique id for a stored record. Backed by a UUIDv4 so concurrent
+/// inserts (e.g. two `add_book` calls landing in the same tick) never
+/// collide the way a timestamp-derived id could.
+pub fn new_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
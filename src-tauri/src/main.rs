@@ -2,13 +2,80 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod commands;
+mod ids;
+mod store;
+
+#[cfg(feature = "clipboard")]
+mod clipboard;
+#[cfg(feature = "notifications")]
+mod reminders;
+#[cfg(feature = "global-shortcut")]
+mod shortcuts;
+
+#[cfg(feature = "notifications")]
+use std::time::Duration;
 
 fn main() {
-    tauri::Builder::default()
+    let mut builder = tauri::Builder::default();
+
+    #[cfg(feature = "notifications")]
+    {
+        builder = builder
+            .plugin(tauri_plugin_notification::init())
+            .manage(reminders::ReminderStore::default());
+    }
+    #[cfg(feature = "global-shortcut")]
+    {
+        builder = builder
+            .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+            .manage(shortcuts::QuickCaptureShortcut::default());
+    }
+
+    builder
+        .manage(store::LibraryStore::default())
+        .setup(|app| {
+            let handle = app.handle().clone();
+            store::load_persisted(&handle)?;
+
+            #[cfg(feature = "notifications")]
+            {
+                reminders::load_persisted(&handle)?;
+
+                let ticker_handle = handle.clone();
+                tauri::async_runtime::spawn(async move {
+                    let mut ticker = tokio::time::interval(Duration::from_secs(60));
+                    loop {
+                        ticker.tick().await;
+                        reminders::check_due_reminders(ticker_handle.clone()).await;
+                    }
+                });
+            }
+
+            #[cfg(feature = "global-shortcut")]
+            shortcuts::init(&handle)?;
+
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
-            commands::greet,
+            #[cfg(feature = "notifications")]
+            commands::schedule_reminder,
+            #[cfg(feature = "notifications")]
+            commands::cancel_reminder,
+            #[cfg(feature = "notifications")]
+            commands::list_reminders,
+            #[cfg(feature = "global-shortcut")]
+            commands::set_quick_capture_shortcut,
+            #[cfg(feature = "global-shortcut")]
+            commands::get_quick_capture_shortcut,
+            commands::add_book,
+            commands::update_progress,
+            commands::log_session,
+            commands::list_books,
+            commands::get_stats,
+            #[cfg(feature = "clipboard")]
+            commands::capture_quote,
             // Adicionar mais comandos aqui conforme implementarmos
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
-}
\ No newline at end of file
+}
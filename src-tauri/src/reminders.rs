@@ -0,0 +1,134 @@
+use std::fs;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+/// A reading reminder for a single book: "notify me every `interval_secs`
+/// seconds with `message` until I cancel it".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reminder {
+    pub id: String,
+    pub book_id: String,
+    pub interval_secs: u64,
+    pub message: String,
+    pub last_fired_at: Option<i64>,
+}
+
+#[derive(Default)]
+pub struct ReminderStore(pub Mutex<Vec<Reminder>>);
+
+fn store_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("failed to resolve app data dir: {e}"))?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join("reminders.json"))
+}
+
+/// Load any reminders persisted from a previous session into app state.
+pub fn load_persisted(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
+    let path = store_path(app)?;
+    if !path.exists() {
+        return Ok(());
+    }
+    let raw = fs::read_to_string(&path)?;
+    let reminders: Vec<Reminder> = serde_json::from_str(&raw).unwrap_or_default();
+    let state = app.state::<ReminderStore>();
+    *state.0.lock().unwrap() = reminders;
+    Ok(())
+}
+
+fn persist(app: &AppHandle, reminders: &[Reminder]) -> Result<(), String> {
+    let path = store_path(app)?;
+    let raw = serde_json::to_string_pretty(reminders).map_err(|e| e.to_string())?;
+    fs::write(path, raw).map_err(|e| e.to_string())
+}
+
+pub fn add(app: &AppHandle, reminder: Reminder) -> Result<(), String> {
+    let state = app.state::<ReminderStore>();
+    let mut reminders = state.0.lock().unwrap();
+    reminders.push(reminder);
+    persist(app, &reminders)
+}
+
+pub fn remove(app: &AppHandle, id: &str) -> Result<(), String> {
+    let state = app.state::<ReminderStore>();
+    let mut reminders = state.0.lock().unwrap();
+    reminders.retain(|r| r.id != id);
+    persist(app, &reminders)
+}
+
+pub fn list(app: &AppHandle) -> Vec<Reminder> {
+    let state = app.state::<ReminderStore>();
+    state.0.lock().unwrap().clone()
+}
+
+/// Called once a minute from the background ticker in `main`. Fires a
+/// desktop notification for every reminder that's due and stamps it so we
+/// don't nag the reader again before its interval has elapsed.
+///
+/// The mutex lock and the `persist` disk write are blocking, so they run
+/// on a blocking-pool thread via `spawn_blocking` instead of the tokio
+/// executor thread the ticker itself runs on.
+pub async fn check_due_reminders(app: AppHandle) {
+    let blocking_app = app.clone();
+    let due = tauri::async_runtime::spawn_blocking(move || {
+        let now = now_unix();
+        let mut due = Vec::new();
+
+        let state = blocking_app.state::<ReminderStore>();
+        let mut reminders = state.0.lock().unwrap();
+        for reminder in reminders.iter_mut() {
+            if is_due(reminder.last_fired_at, reminder.interval_secs, now) {
+                reminder.last_fired_at = Some(now);
+                due.push(reminder.clone());
+            }
+        }
+        let _ = persist(&blocking_app, &reminders);
+        due
+    })
+    .await
+    .unwrap_or_default();
+
+    for reminder in due {
+        let _ = app
+            .notification()
+            .builder()
+            .title("ReadLog")
+            .body(reminder.message)
+            .show();
+    }
+}
+
+fn is_due(last_fired_at: Option<i64>, interval_secs: u64, now: i64) -> bool {
+    match last_fired_at {
+        Some(last) => now - last >= interval_secs as i64,
+        None => true,
+    }
+}
+
+fn now_unix() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_due_fires_on_first_check() {
+        assert!(is_due(None, 60, 1_000));
+    }
+
+    #[test]
+    fn is_due_waits_out_the_interval() {
+        assert!(!is_due(Some(1_000), 60, 1_030));
+        assert!(is_due(Some(1_000), 60, 1_060));
+    }
+}
@@ -0,0 +1,125 @@
+use std::fs;
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+pub const QUICK_CAPTURE_WINDOW: &str = "quick-capture";
+const DEFAULT_ACCELERATOR: &str = "CmdOrCtrl+Shift+L";
+
+#[derive(Default)]
+pub struct QuickCaptureShortcut(pub Mutex<String>);
+
+fn config_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("failed to resolve app data dir: {e}"))?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join("quick_capture_shortcut.txt"))
+}
+
+fn load_persisted(app: &AppHandle) -> String {
+    config_path(app)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| DEFAULT_ACCELERATOR.to_string())
+}
+
+fn persist(app: &AppHandle, accelerator: &str) -> Result<(), String> {
+    let path = config_path(app)?;
+    fs::write(path, accelerator).map_err(|e| e.to_string())
+}
+
+fn show_quick_capture(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window(QUICK_CAPTURE_WINDOW) {
+        let _ = window.show();
+        let _ = window.set_focus();
+        return;
+    }
+
+    let _ = WebviewWindowBuilder::new(
+        app,
+        QUICK_CAPTURE_WINDOW,
+        WebviewUrl::App("quick-capture.html".into()),
+    )
+    .title("Quick capture")
+    .inner_size(360.0, 200.0)
+    .resizable(false)
+    .decorations(true)
+    .build();
+}
+
+fn register(app: &AppHandle, accelerator: &str) -> Result<(), String> {
+    let shortcut: Shortcut = accelerator
+        .parse()
+        .map_err(|e| format!("invalid accelerator '{accelerator}': {e}"))?;
+
+    let handle = app.clone();
+    app.global_shortcut()
+        .on_shortcut(shortcut, move |_app, _shortcut, event| {
+            if event.state() == ShortcutState::Pressed {
+                show_quick_capture(&handle);
+            }
+        })
+        .map_err(|e| e.to_string())
+}
+
+fn unregister(app: &AppHandle, accelerator: &str) {
+    if let Ok(shortcut) = accelerator.parse::<Shortcut>() {
+        let _ = app.global_shortcut().unregister(shortcut);
+    }
+}
+
+/// Register the persisted (or default) quick-capture accelerator. Called
+/// once from `setup`.
+pub fn init(app: &AppHandle) -> Result<(), String> {
+    let accelerator = load_persisted(app);
+    register(app, &accelerator)?;
+    *app.state::<QuickCaptureShortcut>().0.lock().unwrap() = accelerator;
+    Ok(())
+}
+
+pub fn set(app: &AppHandle, accelerator: String) -> Result<(), String> {
+    let state = app.state::<QuickCaptureShortcut>();
+    let previous = state.0.lock().unwrap().clone();
+
+    if !needs_rebind(&previous, &accelerator) {
+        return Ok(());
+    }
+
+    unregister(app, &previous);
+    register(app, &accelerator)?;
+
+    persist(app, &accelerator)?;
+    *state.0.lock().unwrap() = accelerator;
+    Ok(())
+}
+
+pub fn get(app: &AppHandle) -> String {
+    app.state::<QuickCaptureShortcut>().0.lock().unwrap().clone()
+}
+
+/// Whether `set` needs to tear down `previous` and register `new`, or the
+/// binding is unchanged and re-registering the same combo would just
+/// thrash the global-shortcut manager for nothing.
+fn needs_rebind(previous: &str, new: &str) -> bool {
+    previous != new
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn needs_rebind_is_false_when_binding_is_unchanged() {
+        assert!(!needs_rebind("CmdOrCtrl+Shift+L", "CmdOrCtrl+Shift+L"));
+    }
+
+    #[test]
+    fn needs_rebind_is_true_when_binding_changes() {
+        assert!(needs_rebind("CmdOrCtrl+Shift+L", "CmdOrCtrl+Shift+K"));
+    }
+}
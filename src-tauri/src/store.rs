@@ -0,0 +1,289 @@
+use std::fs;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+use crate::ids::new_id;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Book {
+    pub id: String,
+    pub title: String,
+    pub author: String,
+    pub total_pages: Option<u32>,
+    pub current_page: u32,
+    pub added_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadingSession {
+    pub id: String,
+    pub book_id: String,
+    pub started_at: i64,
+    pub duration_secs: u64,
+    pub pages_read: u32,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Progress {
+    pub book_id: String,
+    pub current_page: u32,
+    pub total_pages: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Stats {
+    pub book_id: String,
+    pub sessions_logged: u32,
+    pub total_pages_read: u32,
+    pub total_duration_secs: u64,
+}
+
+#[cfg(feature = "clipboard")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Highlight {
+    pub id: String,
+    pub book_id: String,
+    pub quote: String,
+    pub captured_at: i64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Library {
+    books: Vec<Book>,
+    sessions: Vec<ReadingSession>,
+    #[cfg(feature = "clipboard")]
+    highlights: Vec<Highlight>,
+}
+
+#[derive(Default)]
+pub struct LibraryStore(Mutex<Library>);
+
+fn store_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("failed to resolve app data dir: {e}"))?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join("library.json"))
+}
+
+/// Load the persisted library into app state. Called once from `setup`.
+pub fn load_persisted(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
+    let path = store_path(app)?;
+    if !path.exists() {
+        return Ok(());
+    }
+    let raw = fs::read_to_string(&path)?;
+    let library: Library = serde_json::from_str(&raw).unwrap_or_default();
+    let state = app.state::<LibraryStore>();
+    *state.0.lock().unwrap() = library;
+    Ok(())
+}
+
+fn persist(app: &AppHandle, library: &Library) -> Result<(), String> {
+    let path = store_path(app)?;
+    let raw = serde_json::to_string_pretty(library).map_err(|e| e.to_string())?;
+    fs::write(path, raw).map_err(|e| e.to_string())
+}
+
+pub fn add_book(
+    app: &AppHandle,
+    title: String,
+    author: String,
+    total_pages: Option<u32>,
+) -> Result<Book, String> {
+    let state = app.state::<LibraryStore>();
+    let mut library = state.0.lock().unwrap();
+
+    let book = Book {
+        id: new_id(),
+        title,
+        author,
+        total_pages,
+        current_page: 0,
+        added_at: now_unix(),
+    };
+    library.books.push(book.clone());
+    persist(app, &library)?;
+    Ok(book)
+}
+
+pub fn update_progress(
+    app: &AppHandle,
+    book_id: String,
+    current_page: u32,
+) -> Result<Progress, String> {
+    let state = app.state::<LibraryStore>();
+    let mut library = state.0.lock().unwrap();
+
+    let book = library
+        .books
+        .iter_mut()
+        .find(|b| b.id == book_id)
+        .ok_or_else(|| format!("no book with id '{book_id}'"))?;
+
+    validate_current_page(current_page, book.total_pages)?;
+    book.current_page = current_page;
+    let progress = Progress {
+        book_id: book.id.clone(),
+        current_page: book.current_page,
+        total_pages: book.total_pages,
+    };
+    persist(app, &library)?;
+    Ok(progress)
+}
+
+fn validate_current_page(current_page: u32, total_pages: Option<u32>) -> Result<(), String> {
+    if let Some(total_pages) = total_pages {
+        if current_page > total_pages {
+            return Err(format!(
+                "current_page {current_page} exceeds total_pages {total_pages}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub fn log_session(
+    app: &AppHandle,
+    book_id: String,
+    duration_secs: u64,
+    pages_read: u32,
+    note: Option<String>,
+) -> Result<ReadingSession, String> {
+    let state = app.state::<LibraryStore>();
+    let mut library = state.0.lock().unwrap();
+
+    if !library.books.iter().any(|b| b.id == book_id) {
+        return Err(format!("no book with id '{book_id}'"));
+    }
+
+    let session = ReadingSession {
+        id: new_id(),
+        book_id: book_id.clone(),
+        started_at: now_unix(),
+        duration_secs,
+        pages_read,
+        note,
+    };
+    library.sessions.push(session.clone());
+
+    if let Some(book) = library.books.iter_mut().find(|b| b.id == book_id) {
+        book.current_page = book.current_page.saturating_add(pages_read);
+    }
+
+    persist(app, &library)?;
+    Ok(session)
+}
+
+pub fn list_books(app: &AppHandle) -> Vec<Book> {
+    app.state::<LibraryStore>().0.lock().unwrap().books.clone()
+}
+
+pub fn get_stats(app: &AppHandle, book_id: String) -> Result<Stats, String> {
+    let state = app.state::<LibraryStore>();
+    let library = state.0.lock().unwrap();
+
+    if !library.books.iter().any(|b| b.id == book_id) {
+        return Err(format!("no book with id '{book_id}'"));
+    }
+
+    let sessions: Vec<&ReadingSession> = library
+        .sessions
+        .iter()
+        .filter(|s| s.book_id == book_id)
+        .collect();
+
+    Ok(aggregate_stats(book_id, &sessions))
+}
+
+fn aggregate_stats(book_id: String, sessions: &[&ReadingSession]) -> Stats {
+    Stats {
+        book_id,
+        sessions_logged: sessions.len() as u32,
+        total_pages_read: sessions.iter().map(|s| s.pages_read).sum(),
+        total_duration_secs: sessions.iter().map(|s| s.duration_secs).sum(),
+    }
+}
+
+#[cfg(feature = "clipboard")]
+pub fn add_highlight(app: &AppHandle, book_id: String, quote: String) -> Result<Highlight, String> {
+    let state = app.state::<LibraryStore>();
+    let mut library = state.0.lock().unwrap();
+
+    if !library.books.iter().any(|b| b.id == book_id) {
+        return Err(format!("no book with id '{book_id}'"));
+    }
+
+    let highlight = Highlight {
+        id: new_id(),
+        book_id,
+        quote,
+        captured_at: now_unix(),
+    };
+    library.highlights.push(highlight.clone());
+    persist(app, &library)?;
+    Ok(highlight)
+}
+
+fn now_unix() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(book_id: &str, duration_secs: u64, pages_read: u32) -> ReadingSession {
+        ReadingSession {
+            id: "s1".into(),
+            book_id: book_id.into(),
+            started_at: 0,
+            duration_secs,
+            pages_read,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn validate_current_page_allows_progress_within_total_pages() {
+        assert!(validate_current_page(100, Some(200)).is_ok());
+        assert!(validate_current_page(200, Some(200)).is_ok());
+    }
+
+    #[test]
+    fn validate_current_page_rejects_progress_past_total_pages() {
+        assert!(validate_current_page(201, Some(200)).is_err());
+    }
+
+    #[test]
+    fn validate_current_page_allows_any_value_when_total_pages_unknown() {
+        assert!(validate_current_page(u32::MAX, None).is_ok());
+    }
+
+    #[test]
+    fn aggregate_stats_sums_across_matching_sessions() {
+        let a = session("b1", 600, 20);
+        let b = session("b1", 300, 10);
+        let stats = aggregate_stats("b1".into(), &[&a, &b]);
+
+        assert_eq!(stats.sessions_logged, 2);
+        assert_eq!(stats.total_pages_read, 30);
+        assert_eq!(stats.total_duration_secs, 900);
+    }
+
+    #[test]
+    fn aggregate_stats_is_zeroed_for_no_sessions() {
+        let stats = aggregate_stats("b1".into(), &[]);
+
+        assert_eq!(stats.sessions_logged, 0);
+        assert_eq!(stats.total_pages_read, 0);
+        assert_eq!(stats.total_duration_secs, 0);
+    }
+}